@@ -1,4 +1,5 @@
 use crate::errors::RunOutOfCapacity;
+use core::mem::ManuallyDrop;
 use core::ptr;
 use std::{alloc::{alloc, dealloc, handle_alloc_error, Layout}, ops::Index};
 use core::marker::PhantomData;
@@ -61,13 +62,6 @@ impl<T> StaticHeapArray<T> {
         }
     }
 
-    pub fn get(&self, index: usize) -> Option<T> {
-        match self.is_out_of_index(index) {
-            true => None,
-            false => Some(unsafe { ptr::read(self.pointer.add(index)) }),
-        }
-    }
-
     #[inline]
     pub fn is_out_of_index(&self, index: usize) -> bool {
         index >= self.get_size()
@@ -87,11 +81,68 @@ impl<T> StaticHeapArray<T> {
     pub fn iter(&self) -> StaticHeapArrayIter<'_, T> {
         StaticHeapArrayIter::new(self)
     }
+
+    /// Removes and returns the last element, or `None` if the array is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
+        }
+        self.size -= 1;
+        Some(unsafe { ptr::read(self.pointer.add(self.size)) })
+    }
+
+    /// Removes the element at `index` by moving the last element into its
+    /// place, so it runs in O(1) but does not preserve order.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if self.is_out_of_index(index) {
+            return None;
+        }
+        self.size -= 1;
+        let result = unsafe { ptr::read(self.pointer.add(index)) };
+        if index != self.size {
+            unsafe {
+                let last = ptr::read(self.pointer.add(self.size));
+                ptr::write(self.pointer.add(index), last);
+            }
+        }
+        Some(result)
+    }
+
+    /// Removes the element at `index`, shifting every element after it left
+    /// by one so the remaining elements keep their relative order.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if self.is_out_of_index(index) {
+            return None;
+        }
+        let result = unsafe { ptr::read(self.pointer.add(index)) };
+        let tail_len = self.size - index - 1;
+        if tail_len > 0 {
+            unsafe {
+                ptr::copy(self.pointer.add(index + 1), self.pointer.add(index), tail_len);
+            }
+        }
+        self.size -= 1;
+        Some(result)
+    }
+}
+
+impl<T: Copy> StaticHeapArray<T> {
+    pub fn get(&self, index: usize) -> Option<T> {
+        match self.is_out_of_index(index) {
+            true => None,
+            false => Some(unsafe { ptr::read(self.pointer.add(index)) }),
+        }
+    }
 }
 
 impl<T> Drop for StaticHeapArray<T> {
     fn drop(&mut self) {
-        unsafe { dealloc(self.pointer as *mut u8, self.mem_layout) }
+        unsafe {
+            for i in 0..self.size {
+                ptr::drop_in_place(self.pointer.add(i));
+            }
+            dealloc(self.pointer as *mut u8, self.mem_layout)
+        }
     }
 }
 
@@ -120,17 +171,57 @@ impl<'a, T> StaticHeapArrayIter<'a, T> {
     }
 }
 impl<'a, T> Iterator for StaticHeapArrayIter<'a, T> {
-    type Item = T;
+    type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item> {
         if self.s.is_out_of_index(self.reading_index) {
             return None;
         }
-        let result = self.s.get(self.reading_index);
+        let result = self.s.get_ref(self.reading_index);
         self.reading_index += 1;
         result
     }
 }
 
+pub struct StaticHeapArrayIntoIter<T> {
+    array: ManuallyDrop<StaticHeapArray<T>>,
+    reading_index: usize,
+}
+
+impl<T> Iterator for StaticHeapArrayIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.array.is_out_of_index(self.reading_index) {
+            return None;
+        }
+        let result = unsafe { ptr::read(self.array.pointer.add(self.reading_index)) };
+        self.reading_index += 1;
+        Some(result)
+    }
+}
+
+impl<T> Drop for StaticHeapArrayIntoIter<T> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in self.reading_index..self.array.size {
+                ptr::drop_in_place(self.array.pointer.add(i));
+            }
+            dealloc(self.array.pointer as *mut u8, self.array.mem_layout)
+        }
+    }
+}
+
+impl<T> IntoIterator for StaticHeapArray<T> {
+    type Item = T;
+    type IntoIter = StaticHeapArrayIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StaticHeapArrayIntoIter {
+            array: ManuallyDrop::new(self),
+            reading_index: 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::array::*;
@@ -208,15 +299,76 @@ mod tests {
 
         let mut iter = arr.iter();
         for i in 0..5 {
-            assert_eq!(i + 1, iter.next().unwrap());
+            assert_eq!(&(i + 1), iter.next().unwrap());
         }
         assert!(iter.next().is_none());
 
-        let mut i: i32 = 0;
-        for item in arr.iter() {
-            assert_eq!(i + 1, item);
-            i += 1;
+        for (i, item) in arr.iter().enumerate() {
+            assert_eq!(i as i32 + 1, *item);
         }
         assert!(iter.next().is_none());
     }
+
+    #[test]
+    fn test_static_heap_array_into_iter_transfers_ownership_once() {
+        let mut arr: StaticHeapArray<String> = StaticHeapArray::new(3);
+        arr.push(String::from("a")).unwrap();
+        arr.push(String::from("b")).unwrap();
+        arr.push(String::from("c")).unwrap();
+
+        let collected: Vec<String> = arr.into_iter().collect();
+        assert_eq!(vec!["a", "b", "c"], collected);
+    }
+
+    #[test]
+    fn test_static_heap_array_into_iter_drops_remaining_on_early_drop() {
+        let mut arr: StaticHeapArray<String> = StaticHeapArray::new(3);
+        arr.push(String::from("a")).unwrap();
+        arr.push(String::from("b")).unwrap();
+        arr.push(String::from("c")).unwrap();
+
+        let mut iter = arr.into_iter();
+        assert_eq!(Some(String::from("a")), iter.next());
+        // Dropping `iter` here must drop "b" and "c" without double-dropping "a".
+    }
+
+    #[test]
+    fn test_static_heap_array_pop() {
+        let mut arr: StaticHeapArray<String> = StaticHeapArray::new(3);
+        arr.push(String::from("a")).unwrap();
+        arr.push(String::from("b")).unwrap();
+
+        assert_eq!(Some(String::from("b")), arr.pop());
+        assert_eq!(1, arr.get_size());
+        assert_eq!(Some(String::from("a")), arr.pop());
+        assert_eq!(None, arr.pop());
+    }
+
+    #[test]
+    fn test_static_heap_array_swap_remove() {
+        let mut arr: StaticHeapArray<String> = StaticHeapArray::new(4);
+        arr.push(String::from("a")).unwrap();
+        arr.push(String::from("b")).unwrap();
+        arr.push(String::from("c")).unwrap();
+
+        assert_eq!(Some(String::from("a")), arr.swap_remove(0));
+        assert_eq!(2, arr.get_size());
+        assert_eq!("c", arr.get_ref(0).unwrap());
+        assert_eq!("b", arr.get_ref(1).unwrap());
+        assert_eq!(None, arr.swap_remove(5));
+    }
+
+    #[test]
+    fn test_static_heap_array_remove() {
+        let mut arr: StaticHeapArray<String> = StaticHeapArray::new(4);
+        arr.push(String::from("a")).unwrap();
+        arr.push(String::from("b")).unwrap();
+        arr.push(String::from("c")).unwrap();
+
+        assert_eq!(Some(String::from("a")), arr.remove(0));
+        assert_eq!(2, arr.get_size());
+        assert_eq!("b", arr.get_ref(0).unwrap());
+        assert_eq!("c", arr.get_ref(1).unwrap());
+        assert_eq!(None, arr.remove(5));
+    }
 }
@@ -0,0 +1,12 @@
+use core::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunOutOfCapacity {}
+
+impl fmt::Display for RunOutOfCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "run out of capacity")
+    }
+}
+
+impl core::error::Error for RunOutOfCapacity {}
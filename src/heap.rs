@@ -0,0 +1,170 @@
+use crate::array::StaticHeapArray;
+use crate::errors::RunOutOfCapacity;
+use core::ptr;
+
+/// A fixed-capacity max-heap backed by `StaticHeapArray`.
+///
+/// Elements are kept in array order such that the children of index `i`
+/// live at `2*i+1` and `2*i+2` and the parent of `i` lives at `(i-1)/2`,
+/// with the largest element always at index `0`.
+pub struct StaticHeapBinaryHeap<T: Ord> {
+    data: StaticHeapArray<T>,
+}
+
+impl<T: Ord> StaticHeapBinaryHeap<T> {
+    pub fn new(cap: usize) -> StaticHeapBinaryHeap<T> {
+        StaticHeapBinaryHeap {
+            data: StaticHeapArray::new(cap),
+        }
+    }
+
+    pub fn push(&mut self, item: T) -> Result<(), RunOutOfCapacity> {
+        self.data.push(item)?;
+        self.sift_up(self.data.get_size() - 1);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let size = self.data.get_size();
+        if size == 0 {
+            return None;
+        }
+        self.swap(0, size - 1);
+        let result = self.data.pop();
+        self.sift_down(0, self.data.get_size());
+        result
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.data.get_ref(0)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.get_size()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Consumes the heap and produces its elements in ascending order by
+    /// repeatedly swapping the max into the tail of the backing array, i.e.
+    /// an in-place heapsort.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut heap_len = self.data.get_size();
+        while heap_len > 1 {
+            heap_len -= 1;
+            self.swap(0, heap_len);
+            self.sift_down(0, heap_len);
+        }
+        self.data.into_iter().collect()
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let pa = self.data.get_mut(a).unwrap() as *mut T;
+        let pb = self.data.get_mut(b).unwrap() as *mut T;
+        unsafe { ptr::swap(pa, pb) }
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.data.get_ref(i).unwrap() > self.data.get_ref(parent).unwrap() {
+                self.swap(i, parent);
+                i = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize, bound: usize) {
+        loop {
+            let left = 2 * i + 1;
+            let right = 2 * i + 2;
+            let mut largest = i;
+            if left < bound && self.data.get_ref(left).unwrap() > self.data.get_ref(largest).unwrap() {
+                largest = left;
+            }
+            if right < bound && self.data.get_ref(right).unwrap() > self.data.get_ref(largest).unwrap() {
+                largest = right;
+            }
+            if largest == i {
+                break;
+            }
+            self.swap(i, largest);
+            i = largest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::heap::*;
+
+    #[test]
+    fn test_push_pop_max_order() {
+        let mut heap: StaticHeapBinaryHeap<i32> = StaticHeapBinaryHeap::new(5);
+        heap.push(3).unwrap();
+        heap.push(1).unwrap();
+        heap.push(4).unwrap();
+        heap.push(1).unwrap();
+        heap.push(5).unwrap();
+        assert_eq!(5, heap.len());
+        assert!(heap.push(9).is_err());
+
+        assert_eq!(Some(5), heap.pop());
+        assert_eq!(Some(4), heap.pop());
+        assert_eq!(Some(3), heap.pop());
+        assert_eq!(Some(1), heap.pop());
+        assert_eq!(Some(1), heap.pop());
+        assert_eq!(None, heap.pop());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut heap: StaticHeapBinaryHeap<i32> = StaticHeapBinaryHeap::new(3);
+        assert_eq!(None, heap.peek());
+        heap.push(2).unwrap();
+        heap.push(8).unwrap();
+        heap.push(5).unwrap();
+        assert_eq!(Some(&8), heap.peek());
+    }
+
+    #[test]
+    fn test_reuse_capacity_after_pop() {
+        let mut heap: StaticHeapBinaryHeap<i32> = StaticHeapBinaryHeap::new(2);
+        heap.push(1).unwrap();
+        heap.push(2).unwrap();
+        assert_eq!(Some(2), heap.pop());
+        heap.push(7).unwrap();
+        assert_eq!(Some(7), heap.pop());
+        assert_eq!(Some(1), heap.pop());
+    }
+
+    #[test]
+    fn test_into_sorted_vec() {
+        let mut heap: StaticHeapBinaryHeap<i32> = StaticHeapBinaryHeap::new(6);
+        for item in [5, 3, 8, 1, 9, 2] {
+            heap.push(item).unwrap();
+        }
+        assert_eq!(vec![1, 2, 3, 5, 8, 9], heap.into_sorted_vec());
+    }
+
+    #[test]
+    fn test_heap_of_non_copy_elements() {
+        let mut heap: StaticHeapBinaryHeap<String> = StaticHeapBinaryHeap::new(3);
+        heap.push(String::from("banana")).unwrap();
+        heap.push(String::from("apple")).unwrap();
+        heap.push(String::from("cherry")).unwrap();
+
+        assert_eq!(Some(String::from("cherry")), heap.pop());
+        assert_eq!(Some(String::from("banana")), heap.pop());
+        assert_eq!(Some(String::from("apple")), heap.pop());
+    }
+}
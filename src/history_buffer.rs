@@ -0,0 +1,175 @@
+use core::ptr;
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+
+/// A circular buffer that records the most recent `cap` values written to
+/// it, overwriting the oldest entry once full. Useful for streaming
+/// telemetry and moving averages. Unlike `StaticHeapArray`, `write` never
+/// fails — it just evicts.
+pub struct HistoryBuffer<T> {
+    mem_layout: Layout,
+    pointer: *mut T,
+    cap: usize,
+    write_index: usize,
+    filled: bool,
+}
+
+impl<T> HistoryBuffer<T> {
+    pub fn new(cap: usize) -> HistoryBuffer<T> {
+        assert!(cap > 0, "HistoryBuffer capacity must be greater than zero");
+        let mem_layout = Layout::array::<T>(cap).unwrap();
+        let ptr: *mut u8 = unsafe { alloc(mem_layout) };
+        if ptr.is_null() {
+            handle_alloc_error(mem_layout);
+        }
+        HistoryBuffer {
+            mem_layout,
+            pointer: ptr as *mut T,
+            cap,
+            write_index: 0,
+            filled: false,
+        }
+    }
+
+    pub fn write(&mut self, item: T) {
+        if self.filled {
+            unsafe { ptr::drop_in_place(self.pointer.add(self.write_index)) }
+        }
+        unsafe { ptr::write(self.pointer.add(self.write_index), item) }
+        self.write_index += 1;
+        if self.write_index == self.cap {
+            self.write_index = 0;
+            self.filled = true;
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        if self.filled {
+            self.cap
+        } else {
+            self.write_index
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn get_cap(&self) -> usize {
+        self.cap
+    }
+
+    /// The last value written, or `None` if nothing has been written yet.
+    pub fn recent(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = if self.write_index == 0 {
+            self.cap - 1
+        } else {
+            self.write_index - 1
+        };
+        Some(unsafe { &*self.pointer.add(index) })
+    }
+
+    /// Iterates the buffered values from oldest to newest.
+    pub fn oldest_ordered(&self) -> HistoryBufferIter<'_, T> {
+        let (start, remaining) = if self.filled {
+            (self.write_index, self.cap)
+        } else {
+            (0, self.write_index)
+        };
+        HistoryBufferIter {
+            buffer: self,
+            index: start,
+            remaining,
+        }
+    }
+}
+
+impl<T> Drop for HistoryBuffer<T> {
+    fn drop(&mut self) {
+        let len = self.len();
+        let start = if self.filled { self.write_index } else { 0 };
+        unsafe {
+            for offset in 0..len {
+                let index = (start + offset) % self.cap;
+                ptr::drop_in_place(self.pointer.add(index));
+            }
+            dealloc(self.pointer as *mut u8, self.mem_layout)
+        }
+    }
+}
+
+pub struct HistoryBufferIter<'a, T> {
+    buffer: &'a HistoryBuffer<T>,
+    index: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for HistoryBufferIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = unsafe { &*self.buffer.pointer.add(self.index) };
+        self.index = (self.index + 1) % self.buffer.cap;
+        self.remaining -= 1;
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::history_buffer::*;
+
+    #[test]
+    fn test_write_before_full() {
+        let mut buf: HistoryBuffer<i32> = HistoryBuffer::new(4);
+        buf.write(1);
+        buf.write(2);
+
+        assert_eq!(2, buf.len());
+        assert_eq!(Some(&2), buf.recent());
+        assert_eq!(vec![&1, &2], buf.oldest_ordered().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_write_evicts_oldest_once_full() {
+        let mut buf: HistoryBuffer<i32> = HistoryBuffer::new(3);
+        buf.write(1);
+        buf.write(2);
+        buf.write(3);
+        buf.write(4);
+        buf.write(5);
+
+        assert_eq!(3, buf.len());
+        assert_eq!(Some(&5), buf.recent());
+        assert_eq!(vec![&3, &4, &5], buf.oldest_ordered().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_empty_buffer() {
+        let buf: HistoryBuffer<i32> = HistoryBuffer::new(3);
+        assert!(buf.is_empty());
+        assert_eq!(None, buf.recent());
+        assert_eq!(Vec::<&i32>::new(), buf.oldest_ordered().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_write_drops_evicted_value() {
+        let mut buf: HistoryBuffer<String> = HistoryBuffer::new(2);
+        buf.write(String::from("a"));
+        buf.write(String::from("b"));
+        buf.write(String::from("c"));
+
+        assert_eq!(
+            vec!["b", "c"],
+            buf.oldest_ordered().map(|s| s.as_str()).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use crate::errors::RunOutOfCapacity;
+use core::mem::MaybeUninit;
+use core::ops::Index;
+use core::ptr;
+
+/// A const-generic sibling of `StaticHeapArray` that stores its `N`
+/// elements inline rather than behind an `alloc`, so it works with no
+/// global allocator (e.g. `#![no_std]`). Building this crate with
+/// `default-features = false` compiles only `InlineArray` and `errors`;
+/// the other containers in this crate need the default `std` feature.
+pub struct InlineArray<T, const N: usize> {
+    data: MaybeUninit<[T; N]>,
+    size: usize,
+}
+
+impl<T, const N: usize> Default for InlineArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> InlineArray<T, N> {
+    pub fn new() -> InlineArray<T, N> {
+        InlineArray {
+            data: MaybeUninit::uninit(),
+            size: 0,
+        }
+    }
+
+    pub fn from<const SIZE: usize>(arr: [T; SIZE]) -> InlineArray<T, N> {
+        const { assert!(SIZE <= N, "SIZE must not exceed N") };
+        let mut r = InlineArray::new();
+        for item in arr {
+            r.push(item).unwrap();
+        }
+        r
+    }
+
+    pub fn push(&mut self, item: T) -> Result<(), RunOutOfCapacity> {
+        if self.is_full() {
+            return Err(RunOutOfCapacity {});
+        }
+        unsafe { ptr::write(self.as_mut_ptr().add(self.size), item) }
+        self.size += 1;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.size == self.get_cap()
+    }
+
+    pub fn get_ref(&self, index: usize) -> Option<&T> {
+        match self.is_out_of_index(index) {
+            true => None,
+            false => Some(unsafe { &*self.as_ptr().add(index) }),
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self.is_out_of_index(index) {
+            true => None,
+            false => Some(unsafe { &mut *self.as_mut_ptr().add(index) }),
+        }
+    }
+
+    #[inline]
+    pub fn is_out_of_index(&self, index: usize) -> bool {
+        index >= self.get_size()
+    }
+
+    #[inline]
+    pub const fn get_cap(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    pub fn iter(&self) -> InlineArrayIter<'_, T, N> {
+        InlineArrayIter::new(self)
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+}
+
+impl<T: Copy, const N: usize> InlineArray<T, N> {
+    pub fn get(&self, index: usize) -> Option<T> {
+        match self.is_out_of_index(index) {
+            true => None,
+            false => Some(unsafe { ptr::read(self.as_ptr().add(index)) }),
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for InlineArray<T, N> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in 0..self.size {
+                ptr::drop_in_place(self.as_mut_ptr().add(i));
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Index<usize> for InlineArray<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &Self::Output {
+        self.get_ref(idx).unwrap()
+    }
+}
+
+pub struct InlineArrayIter<'a, T, const N: usize> {
+    s: &'a InlineArray<T, N>,
+    reading_index: usize,
+}
+
+impl<'a, T, const N: usize> InlineArrayIter<'a, T, N> {
+    pub fn new(array: &'a InlineArray<T, N>) -> Self {
+        Self {
+            s: array,
+            reading_index: 0,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for InlineArrayIter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.s.is_out_of_index(self.reading_index) {
+            return None;
+        }
+        let result = self.s.get_ref(self.reading_index);
+        self.reading_index += 1;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::*;
+    use crate::inline_array::*;
+
+    #[test]
+    fn test_inline_array_new() {
+        let mut arr: InlineArray<i32, 5> = InlineArray::new();
+
+        arr.push(1).unwrap();
+        arr.push(2).unwrap();
+        arr.push(3).unwrap();
+        arr.push(4).unwrap();
+        arr.push(5).unwrap();
+        assert!(arr.is_full());
+        assert_eq!(RunOutOfCapacity {}, arr.push(6).unwrap_err());
+
+        assert_eq!(1, arr.get(0).unwrap());
+        assert_eq!(5, arr.get(4).unwrap());
+        assert_eq!(None, arr.get(5));
+        assert_eq!(5, arr.get_cap());
+    }
+
+    #[test]
+    fn test_inline_array_from_initialed_array() {
+        let arr: InlineArray<i32, 5> = InlineArray::from([1, 2, 3, 4, 5]);
+
+        assert!(arr.is_full());
+        assert_eq!(1, arr[0]);
+        assert_eq!(5, arr[4]);
+    }
+
+    #[test]
+    fn test_inline_array_get_mut() {
+        let mut arr: InlineArray<i32, 5> = InlineArray::from([1, 2, 3, 4, 5]);
+
+        let item3 = arr.get_mut(2).unwrap();
+        *item3 = 99;
+        assert_eq!(99, arr.get(2).unwrap());
+    }
+
+    #[test]
+    fn test_inline_array_iter() {
+        let arr: InlineArray<i32, 5> = InlineArray::from([1, 2, 3, 4, 5]);
+
+        for (i, item) in arr.iter().enumerate() {
+            assert_eq!(i as i32 + 1, *item);
+        }
+    }
+
+    // Needs `String`, which isn't available without `std`/`alloc`.
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_inline_array_drops_elements() {
+        let mut arr: InlineArray<String, 3> = InlineArray::new();
+        arr.push(String::from("a")).unwrap();
+        arr.push(String::from("b")).unwrap();
+        drop(arr);
+    }
+}
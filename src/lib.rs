@@ -1,5 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 pub mod array;
 pub mod errors;
+#[cfg(feature = "std")]
+pub mod heap;
+#[cfg(feature = "std")]
+pub mod history_buffer;
+pub mod inline_array;
+#[cfg(feature = "std")]
+pub mod pool;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+#[cfg(feature = "std")]
+pub mod spsc;
 
+#[cfg(feature = "std")]
 pub use self::array::StaticHeapArray;
 pub use self::errors::*;
+#[cfg(feature = "std")]
+pub use self::heap::StaticHeapBinaryHeap;
+#[cfg(feature = "std")]
+pub use self::history_buffer::HistoryBuffer;
+pub use self::inline_array::InlineArray;
+#[cfg(feature = "std")]
+pub use self::pool::Pool;
+#[cfg(feature = "std")]
+pub use self::spsc::Queue as SpscQueue;
@@ -0,0 +1,241 @@
+use crate::errors::RunOutOfCapacity;
+use core::ptr;
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Sentinel "no slot" index, also the upper bound on `cap`.
+const NIL: u32 = u32::MAX;
+
+// The freelist head packs a 32-bit ABA tag and a 32-bit slot index into one
+// `AtomicU64` so both can be updated together in a single `compare_exchange`.
+// This is sized explicitly rather than derived from `usize` so it packs the
+// same way regardless of the target's pointer width (`usize << 32` would be
+// a compile-time overflow on 32-bit targets).
+fn pack(tag: u32, index: u32) -> u64 {
+    ((tag as u64) << 32) | index as u64
+}
+
+fn unpack(packed: u64) -> (u32, u32) {
+    ((packed >> 32) as u32, packed as u32)
+}
+
+struct Inner<T> {
+    mem_layout: Layout,
+    pointer: *mut T,
+    // `next[i]` is the freelist link for slot `i`; `NIL` terminates the list.
+    next: Box<[AtomicU32]>,
+    head: AtomicU64,
+}
+
+unsafe impl<T: Send> Send for Inner<T> {}
+// `PoolBox::deref`/`deref_mut` hand out `&T`/`&mut T` through this same
+// `Arc<Inner<T>>`, so `Inner<T>` (and therefore `PoolBox<T>`) must only be
+// `Sync` when `T` itself is, or a `!Sync` `T` like `Cell` could be accessed
+// from multiple threads with no synchronization.
+unsafe impl<T: Send + Sync> Sync for Inner<T> {}
+
+impl<T> Inner<T> {
+    fn new(cap: usize) -> Inner<T> {
+        assert!(
+            cap > 0 && cap < NIL as usize,
+            "Pool capacity out of range"
+        );
+        let mem_layout = Layout::array::<T>(cap).unwrap();
+        let ptr: *mut u8 = unsafe { alloc(mem_layout) };
+        if ptr.is_null() {
+            handle_alloc_error(mem_layout);
+        }
+        let next = (0..cap as u32)
+            .map(|i| AtomicU32::new(if i + 1 == cap as u32 { NIL } else { i + 1 }))
+            .collect();
+        Inner {
+            mem_layout,
+            pointer: ptr as *mut T,
+            next,
+            head: AtomicU64::new(pack(0, 0)),
+        }
+    }
+
+    /// Pops a free slot off the Treiber stack, tagging the head on every
+    /// swap so a slot that gets freed and re-allocated between our load and
+    /// our `compare_exchange` cannot fool us into accepting a stale head (ABA).
+    fn alloc_slot(&self) -> Option<usize> {
+        loop {
+            let packed = self.head.load(Ordering::Acquire);
+            let (tag, index) = unpack(packed);
+            if index == NIL {
+                return None;
+            }
+            let next_index = self.next[index as usize].load(Ordering::Relaxed);
+            let new_packed = pack(tag.wrapping_add(1), next_index);
+            if self
+                .head
+                .compare_exchange_weak(packed, new_packed, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Some(index as usize);
+            }
+        }
+    }
+
+    fn free_slot(&self, index: usize) {
+        let index = index as u32;
+        loop {
+            let packed = self.head.load(Ordering::Acquire);
+            let (tag, old_index) = unpack(packed);
+            self.next[index as usize].store(old_index, Ordering::Relaxed);
+            let new_packed = pack(tag.wrapping_add(1), index);
+            if self
+                .head
+                .compare_exchange_weak(packed, new_packed, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        // Every `PoolBox` holds a clone of the `Arc<Inner<T>>`, so by the
+        // time the last reference is dropped every slot has already been
+        // freed (and its element destructor already run) by `PoolBox::drop`.
+        unsafe { dealloc(self.pointer as *mut u8, self.mem_layout) }
+    }
+}
+
+/// A lock-free, fixed-capacity pool of uniformly sized blocks, reused via a
+/// Treiber-stack freelist so `alloc`/the returned handle's `drop` never
+/// take a lock. Useful as a bounded allocator for hot paths where a
+/// per-allocation `malloc` is too slow.
+pub struct Pool<T> {
+    inner: Arc<Inner<T>>,
+}
+
+unsafe impl<T: Send> Send for Pool<T> {}
+unsafe impl<T: Send> Sync for Pool<T> {}
+
+impl<T> Pool<T> {
+    pub fn new(cap: usize) -> Pool<T> {
+        Pool {
+            inner: Arc::new(Inner::new(cap)),
+        }
+    }
+
+    /// Hands out a `PoolBox` holding `item`, or `RunOutOfCapacity` if every
+    /// block is currently checked out.
+    pub fn alloc(&self, item: T) -> Result<PoolBox<T>, RunOutOfCapacity> {
+        match self.inner.alloc_slot() {
+            Some(index) => {
+                unsafe { ptr::write(self.inner.pointer.add(index), item) }
+                Ok(PoolBox {
+                    inner: self.inner.clone(),
+                    index,
+                })
+            }
+            None => Err(RunOutOfCapacity {}),
+        }
+    }
+}
+
+impl<T> Clone for Pool<T> {
+    fn clone(&self) -> Pool<T> {
+        Pool {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// An RAII handle to a block checked out of a `Pool`. Drops its element and
+/// returns the block to the pool's freelist when it goes out of scope.
+pub struct PoolBox<T> {
+    inner: Arc<Inner<T>>,
+    index: usize,
+}
+
+unsafe impl<T: Send> Send for PoolBox<T> {}
+
+impl<T> Deref for PoolBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.inner.pointer.add(self.index) }
+    }
+}
+
+impl<T> DerefMut for PoolBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.inner.pointer.add(self.index) }
+    }
+}
+
+impl<T> Drop for PoolBox<T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.inner.pointer.add(self.index)) }
+        self.inner.free_slot(self.index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pool::*;
+
+    #[test]
+    fn test_alloc_and_reuse_after_drop() {
+        let pool: Pool<i32> = Pool::new(2);
+
+        let a = pool.alloc(1).unwrap();
+        let b = pool.alloc(2).unwrap();
+        assert!(pool.alloc(3).is_err());
+
+        drop(a);
+        let c = pool.alloc(3).unwrap();
+        assert_eq!(3, *c);
+        assert_eq!(2, *b);
+    }
+
+    #[test]
+    fn test_drop_runs_destructor_of_checked_out_value() {
+        let pool: Pool<String> = Pool::new(1);
+
+        let handle = pool.alloc(String::from("hello")).unwrap();
+        assert_eq!("hello", &*handle);
+        drop(handle);
+
+        let handle = pool.alloc(String::from("world")).unwrap();
+        assert_eq!("world", &*handle);
+    }
+
+    #[test]
+    fn test_deref_mut() {
+        let pool: Pool<i32> = Pool::new(1);
+        let mut handle = pool.alloc(1).unwrap();
+        *handle += 41;
+        assert_eq!(42, *handle);
+    }
+
+    #[test]
+    fn test_concurrent_alloc_and_free() {
+        let pool: Pool<i32> = Pool::new(4);
+        let mut threads = Vec::new();
+        for t in 0..4 {
+            let pool = pool.clone();
+            threads.push(std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    let handle = loop {
+                        if let Ok(handle) = pool.alloc(t) {
+                            break handle;
+                        }
+                    };
+                    assert_eq!(t, *handle);
+                }
+            }));
+        }
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! `Serialize`/`Deserialize` support for `StaticHeapArray`, enabled by the
+//! `serde` feature so these containers round-trip through JSON/bincode
+//! without the caller manually copying into a `Vec` first.
+use crate::array::StaticHeapArray;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, Error as DeError, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+impl<T: Serialize> Serialize for StaticHeapArray<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.get_size()))?;
+        for item in self.iter() {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for StaticHeapArray<T> {
+    /// Deserializes a sequence into a `StaticHeapArray` sized to match it
+    /// exactly, so every element always fits.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let items: Vec<T> = Vec::deserialize(deserializer)?;
+        let mut arr = StaticHeapArray::new(items.len());
+        for item in items {
+            arr.push(item).map_err(D::Error::custom)?;
+        }
+        Ok(arr)
+    }
+}
+
+/// Deserializes a sequence into a `StaticHeapArray` bounded to `cap`,
+/// erroring cleanly instead of panicking if the input holds more elements
+/// than the caller is willing to allocate for.
+pub fn deserialize_bounded<'de, D, T>(deserializer: D, cap: usize) -> Result<StaticHeapArray<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct BoundedVisitor<T> {
+        cap: usize,
+        _marker: PhantomData<T>,
+    }
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for BoundedVisitor<T> {
+        type Value = StaticHeapArray<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a sequence of at most {} elements", self.cap)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut arr = StaticHeapArray::new(self.cap);
+            while let Some(item) = seq.next_element()? {
+                arr.push(item)
+                    .map_err(|_| A::Error::invalid_length(arr.get_cap() + 1, &self))?;
+            }
+            Ok(arr)
+        }
+    }
+
+    deserializer.deserialize_seq(BoundedVisitor {
+        cap,
+        _marker: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::StaticHeapArray;
+
+    #[test]
+    fn test_serialize_deserialize_round_trip() {
+        let arr: StaticHeapArray<i32> = StaticHeapArray::from([1, 2, 3, 4, 5]);
+
+        let json = serde_json::to_string(&arr).unwrap();
+        let restored: StaticHeapArray<i32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(arr.get_size(), restored.get_size());
+        for i in 0..arr.get_size() {
+            assert_eq!(arr.get_ref(i), restored.get_ref(i));
+        }
+    }
+
+    #[test]
+    fn test_deserialize_bounded_rejects_oversized_input() {
+        let json = "[1, 2, 3]";
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+
+        let result: Result<StaticHeapArray<i32>, _> =
+            super::deserialize_bounded(&mut deserializer, 2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_bounded_accepts_input_within_bound() {
+        let json = "[1, 2, 3]";
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+
+        let arr: StaticHeapArray<i32> = super::deserialize_bounded(&mut deserializer, 5).unwrap();
+        assert_eq!(3, arr.get_size());
+        assert_eq!(Some(&1), arr.get_ref(0));
+        assert_eq!(Some(&3), arr.get_ref(2));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::errors::RunOutOfCapacity;
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct Shared<T> {
+    mem_layout: Layout,
+    pointer: *mut T,
+    cap: usize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn new(cap: usize) -> Shared<T> {
+        assert!(cap >= 2, "spsc::Queue needs at least 2 slots to hold 1 item");
+        let mem_layout = Layout::array::<T>(cap).unwrap();
+        let ptr: *mut u8 = unsafe { alloc(mem_layout) };
+        if ptr.is_null() {
+            handle_alloc_error(mem_layout);
+        }
+        Shared {
+            mem_layout,
+            pointer: ptr as *mut T,
+            cap,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let mut head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        unsafe {
+            while head != tail {
+                ptr::drop_in_place(self.pointer.add(head));
+                head = (head + 1) % self.cap;
+            }
+            dealloc(self.pointer as *mut u8, self.mem_layout)
+        }
+    }
+}
+
+/// A single-producer single-consumer wait-free bounded FIFO, for handing
+/// data between one producer thread/ISR and one consumer.
+///
+/// `cap` slots are allocated but only `cap - 1` can ever be occupied at
+/// once: one slot is sacrificed so the full and empty states stay
+/// distinguishable from one another.
+pub struct Queue<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Queue<T> {
+    pub fn new(cap: usize) -> Queue<T> {
+        Queue {
+            shared: Arc::new(Shared::new(cap)),
+        }
+    }
+
+    /// Splits the queue into a `Producer` and a `Consumer` that can be
+    /// handed to separate threads.
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        (
+            Producer {
+                shared: self.shared.clone(),
+                _not_sync: PhantomData,
+            },
+            Consumer {
+                shared: self.shared,
+                _not_sync: PhantomData,
+            },
+        )
+    }
+}
+
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+    // Blocks the auto-derived `Sync` impl that `Arc<Shared<T>>` would
+    // otherwise hand us (`Shared<T>` is `unsafe impl Sync`). `enqueue` reads
+    // `tail` and writes a slot without synchronizing against another
+    // `enqueue` call, so sharing one `Producer` across threads (e.g. via
+    // `Arc<Producer<T>>`) would race; only the single producer may call it.
+    _not_sync: PhantomData<*const ()>,
+}
+
+unsafe impl<T: Send> Send for Producer<T> {}
+
+impl<T> Producer<T> {
+    pub fn enqueue(&self, item: T) -> Result<(), RunOutOfCapacity> {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let next = (tail + 1) % self.shared.cap;
+        if next == self.shared.head.load(Ordering::Acquire) {
+            return Err(RunOutOfCapacity {});
+        }
+        unsafe { ptr::write(self.shared.pointer.add(tail), item) }
+        self.shared.tail.store(next, Ordering::Release);
+        Ok(())
+    }
+}
+
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+    // See `Producer::_not_sync`: only the single consumer may call `dequeue`.
+    _not_sync: PhantomData<*const ()>,
+}
+
+unsafe impl<T: Send> Send for Consumer<T> {}
+
+impl<T> Consumer<T> {
+    pub fn dequeue(&self) -> Option<T> {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        if head == self.shared.tail.load(Ordering::Acquire) {
+            return None;
+        }
+        let item = unsafe { ptr::read(self.shared.pointer.add(head)) };
+        self.shared
+            .head
+            .store((head + 1) % self.shared.cap, Ordering::Release);
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spsc::*;
+
+    #[test]
+    fn test_enqueue_dequeue_order() {
+        let queue: Queue<i32> = Queue::new(4);
+        let (producer, consumer) = queue.split();
+
+        producer.enqueue(1).unwrap();
+        producer.enqueue(2).unwrap();
+        assert_eq!(Some(1), consumer.dequeue());
+        assert_eq!(Some(2), consumer.dequeue());
+        assert_eq!(None, consumer.dequeue());
+    }
+
+    #[test]
+    fn test_full_queue_rejects_enqueue() {
+        let queue: Queue<i32> = Queue::new(3);
+        let (producer, _consumer) = queue.split();
+
+        producer.enqueue(1).unwrap();
+        producer.enqueue(2).unwrap();
+        assert_eq!(RunOutOfCapacity {}, producer.enqueue(3).unwrap_err());
+    }
+
+    #[test]
+    fn test_wrap_around() {
+        let queue: Queue<i32> = Queue::new(3);
+        let (producer, consumer) = queue.split();
+
+        for round in 0..5 {
+            producer.enqueue(round).unwrap();
+            producer.enqueue(round * 10).unwrap();
+            assert_eq!(Some(round), consumer.dequeue());
+            assert_eq!(Some(round * 10), consumer.dequeue());
+            assert_eq!(None, consumer.dequeue());
+        }
+    }
+
+    #[test]
+    fn test_producer_consumer_across_threads() {
+        let queue: Queue<i32> = Queue::new(16);
+        let (producer, consumer) = queue.split();
+
+        let producer_thread = std::thread::spawn(move || {
+            for i in 0..1000 {
+                while producer.enqueue(i).is_err() {}
+            }
+        });
+
+        let mut received = Vec::with_capacity(1000);
+        while received.len() < 1000 {
+            if let Some(item) = consumer.dequeue() {
+                received.push(item);
+            }
+        }
+        producer_thread.join().unwrap();
+
+        assert_eq!((0..1000).collect::<Vec<i32>>(), received);
+    }
+
+    #[test]
+    fn test_drop_runs_destructors_of_unconsumed_items() {
+        let queue: Queue<String> = Queue::new(4);
+        let (producer, consumer) = queue.split();
+
+        producer.enqueue(String::from("a")).unwrap();
+        producer.enqueue(String::from("b")).unwrap();
+        assert_eq!(Some(String::from("a")), consumer.dequeue());
+        // "b" is still queued; dropping both ends here must drop it once.
+    }
+}